@@ -0,0 +1,10 @@
+//! Submodule providing the `TypedConcat` trait for joining two tuples into
+//! one, the inverse of `TypedTuple::split_at`.
+
+typed_tuple_macros::define_typed_concat_trait!();
+
+#[cfg(not(feature = "large-tuples"))]
+typed_tuple_macros::impl_typed_concat_trait!(12);
+
+#[cfg(feature = "large-tuples")]
+typed_tuple_macros::impl_typed_concat_trait!(32);
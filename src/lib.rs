@@ -32,40 +32,78 @@
 //! assert_eq!(tuple, (10, Some(Type0), None, Some(Type2)));
 //! ```
 //!
+//! ## Beyond `get`/`map`
+//!
+//! A handful of sibling traits cover whole-tuple operations that `TypedTuple` itself
+//! does not express:
+//!
+//! - [`TypedPush`] appends or prepends a value, building a larger tuple.
+//! - [`TypedConcat`] joins two tuples end to end, the inverse of `TypedTuple::split_at`.
+//! - [`TypedZip`]/[`TypedUnzip`] pair up and split apart two equal-length tuples.
+//! - [`TupleTraversal`], driven by a [`TupleVisitor`], runs a polymorphic operation
+//! (serialize, debug-print, hash, ...) across every field regardless of its type.
+//! - [`TypedMapAll`], driven by a [`TupleMapper`], transforms every field at once and
+//! reconstructs the tuple, without requiring `T: Default` the way `TypedTuple::map` does.
+//! - [`TypedLast`] gets at the last element of a tuple by type.
+//!
 //! ## Limitations
 //!
-//! - Fields of the same type must still specify a constant index. This can be specified
-//! with, for example, `TypedTuple::<1, _>::get(&tuple)` where `1` is the element index,
-//! however this offers no advantage over simply calling `tuple.1`.
+//! - Fields of the same type must still specify an index. This can be specified with, for
+//! example, `TypedTuple::<TupleIndex1, _>::get(&tuple)` where `TupleIndex1` marks the
+//! element index, however this offers no advantage over simply calling `tuple.1`.
 //! - `typed_tuple` can impact readability. Types should be explicit if not immediately
 //! obvious. Prefer `let a: usize = tuple.get()` over `let a = tuple.get()`.
 //! - `TypedTuple` is implemented on tuples of up to 12 elements in length. This was chosen
 //! as it is the limit of many tuple trait implementations (`PartialEq`, `Eq`, etc.),
-//! however can be extended to support a higher number of elements if needed.
+//! however can be extended to support a higher number of elements if needed. Enabling
+//! the `large-tuples` feature raises this ceiling to 32 elements.
+
+mod concat;
+mod mapper;
+mod push;
+mod typed_last;
+mod visitor;
+mod zip;
+
+pub use concat::TypedConcat;
+pub use mapper::{TupleMapper, TypedMapAll};
+pub use push::TypedPush;
+pub use typed_last::TypedLast;
+pub use visitor::{TupleTraversal, TupleVisitor};
+pub use zip::{TypedUnzip, TypedZip};
 
 /// Trait for tuple element manipulation by type.
-pub trait TypedTuple<const INDEX: usize, T> {
+///
+/// `Index` is one of the generated `TupleIndexN` marker types rather than a
+/// const generic, since Rust does not yet support const generics in traits
+/// used this way (see [rust-lang/rust#76560](https://github.com/rust-lang/rust/issues/76560)).
+/// The associated [`TypedTuple::INDEX`] constant exposes that position as a
+/// plain `usize` for introspection.
+pub trait TypedTuple<Index, T> {
+    /// The numeric position of `T` within the tuple.
+    const INDEX: usize;
+
     /// Get a reference to the element of type `T`.
     /// # Example
     /// ```
-    /// # use typed_tuple::TypedTuple;
+    /// # use typed_tuple::{TypedTuple, TupleIndex0, TupleIndex1, TupleIndex2};
     /// // Get by type.
     /// let tuple = ("a", 'b', 2usize);
     /// let a: &&str = tuple.get();
     /// let b: &char = tuple.get();
     /// let c: &usize = tuple.get();
     ///
-    /// // Get by 'const' index.
-    /// let a = TypedTuple::<0, _>::get(&tuple);
-    /// let b = TypedTuple::<1, _>::get(&tuple);
-    /// let c = TypedTuple::<2, _>::get(&tuple);
+    /// // Get by marker-type index.
+    /// let a = TypedTuple::<TupleIndex0, _>::get(&tuple);
+    /// let b = TypedTuple::<TupleIndex1, _>::get(&tuple);
+    /// let c = TypedTuple::<TupleIndex2, _>::get(&tuple);
     /// ```
     fn get(&self) -> &T;
 
     /// Get a mutable reference to the element of type `T`.
     /// # Example
     /// ```
-    /// # use typed_tuple::TypedTuple;
+    /// # use typed_tuple::{TypedTuple, TupleIndex0, TupleIndex1, TupleIndex2};
     /// // Mutate by type.
     /// let mut tuple = ("a", 'b', 2usize);
     /// *tuple.get_mut() = "c";
@@ -73,10 +111,10 @@ pub trait TypedTuple<const INDEX: usize, T> {
     /// *tuple.get_mut() = 3usize;
     /// assert_eq!(tuple, ("c", 'd', 3));
     ///
-    /// // Mutate by 'const' index.
-    /// *TypedTuple::<0, _>::get_mut(&mut tuple) = "e";
-    /// *TypedTuple::<1, _>::get_mut(&mut tuple) = 'f';
-    /// *TypedTuple::<2, _>::get_mut(&mut tuple) = 4usize;
+    /// // Mutate by marker-type index.
+    /// *TypedTuple::<TupleIndex0, _>::get_mut(&mut tuple) = "e";
+    /// *TypedTuple::<TupleIndex1, _>::get_mut(&mut tuple) = 'f';
+    /// *TypedTuple::<TupleIndex2, _>::get_mut(&mut tuple) = 4usize;
     /// assert_eq!(tuple, ("e", 'f', 4))
     /// ```
     fn get_mut(&mut self) -> &mut T;
@@ -84,7 +122,7 @@ pub trait TypedTuple<const INDEX: usize, T> {
     /// Takes a closure, mutating the element of type `T`.
     /// # Example
     /// ```
-    /// # use typed_tuple::TypedTuple;
+    /// # use typed_tuple::{TypedTuple, TupleIndex0, TupleIndex1, TupleIndex2};
     /// // Map by type.
     /// let mut tuple = ("a".to_string(), 1u8, 2usize);
     /// tuple.map(|el: String| el.to_uppercase());
@@ -92,53 +130,54 @@ pub trait TypedTuple<const INDEX: usize, T> {
     /// tuple.map(|el: usize| el + 2);
     /// assert_eq!(tuple, ("A".to_string(), 2, 4));
     ///
-    /// // Map by 'const' index.
-    /// TypedTuple::<0, _>::map(&mut tuple, |el| el.to_lowercase());
-    /// TypedTuple::<1, _>::map(&mut tuple, |el| el - 1);
-    /// TypedTuple::<2, _>::map(&mut tuple, |el| el - 2);
+    /// // Map by marker-type index.
+    /// TypedTuple::<TupleIndex0, _>::map(&mut tuple, |el| el.to_lowercase());
+    /// TypedTuple::<TupleIndex1, _>::map(&mut tuple, |el| el - 1);
+    /// TypedTuple::<TupleIndex2, _>::map(&mut tuple, |el| el - 2);
     /// assert_eq!(tuple, ("a".to_string(), 1, 2))
     /// ```
     fn map<FN: FnOnce(T) -> T>(&mut self, f: FN)
     where
         T: Default;
-}
 
-macro_rules! impl_typed_tuple {
-    (( $($generics:tt ),* ), [ $( $( $idx_tail:tt ),+ )? ], []) => {};
+    /// The tuple made up of every element other than the one of type `T`,
+    /// in their original relative order.
+    type PopOutput;
 
-    (( $($generics:tt ),* ), [$idx_head:tt  $(, $idx_tail:tt )* ], [ $gen_head:tt $(, $gen_tail:tt )* ]) => {
-        impl< $( $generics ),+ > TypedTuple<$idx_head, $gen_head> for ( $( $generics ),+ ) {
-            fn get(&self) -> &$gen_head {
-                &self.$idx_head
-            }
+    /// The tuple made up of every element up to and including the one of
+    /// type `T`.
+    type SplitLeft;
 
-            fn get_mut(&mut self) -> &mut $gen_head {
-                &mut self.$idx_head
-            }
+    /// The tuple made up of every element after the one of type `T`.
+    type SplitRight;
 
-            fn map<FN: FnOnce($gen_head) -> $gen_head>(&mut self, f: FN) where $gen_head: Default {
-                self.$idx_head = f(std::mem::take(&mut self.$idx_head));
-            }
-        }
-        impl_typed_tuple!(($( $generics ),* ), [ $( $idx_tail ),* ], [ $( $gen_tail ),* ]);
-    };
+    /// Consumes the tuple, returning the element of type `T` and the
+    /// remaining elements.
+    /// # Example
+    /// ```
+    /// # use typed_tuple::TypedTuple;
+    /// let tuple = ("a", 'b', 2usize);
+    /// let (b, rest): (char, _) = tuple.pop();
+    /// assert_eq!(b, 'b');
+    /// assert_eq!(rest, ("a", 2usize));
+    /// ```
+    fn pop(self) -> (T, Self::PopOutput);
 
-    (( $($generics:tt),* )) => {
-        impl_typed_tuple!(
-            ( $( $generics ),* ),
-            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
-            [ $( $generics ),* ]);
-    }
+    /// Consumes the tuple, bisecting it around the element of type `T`,
+    /// which is included in the left half.
+    /// # Example
+    /// ```
+    /// # use typed_tuple::{TypedTuple, TupleIndex1};
+    /// let tuple = ("a", 'b', 2usize);
+    /// let (left, right) = TypedTuple::<TupleIndex1, _>::split_at(tuple);
+    /// assert_eq!(left, ("a", 'b'));
+    /// assert_eq!(right, (2usize,));
+    /// ```
+    fn split_at(self) -> (Self::SplitLeft, Self::SplitRight);
 }
 
-impl_typed_tuple!((A, B));
-impl_typed_tuple!((A, B, C));
-impl_typed_tuple!((A, B, C, D));
-impl_typed_tuple!((A, B, C, D, E));
-impl_typed_tuple!((A, B, C, D, E, F));
-impl_typed_tuple!((A, B, C, D, E, F, G));
-impl_typed_tuple!((A, B, C, D, E, F, G, H));
-impl_typed_tuple!((A, B, C, D, E, F, G, H, I));
-impl_typed_tuple!((A, B, C, D, E, F, G, H, I, K));
-impl_typed_tuple!((A, B, C, D, E, F, G, H, I, K, J));
-impl_typed_tuple!((A, B, C, D, E, F, G, H, I, K, J, L));
+#[cfg(not(feature = "large-tuples"))]
+typed_tuple_macros::generate_typed_tuple_impls!(12);
+
+#[cfg(feature = "large-tuples")]
+typed_tuple_macros::generate_typed_tuple_impls!(32);
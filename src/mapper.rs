@@ -0,0 +1,10 @@
+//! Submodule providing `TupleMapper`-driven transformation of every field of
+//! a tuple at once.
+
+typed_tuple_macros::define_tuple_mapper_trait!();
+
+#[cfg(not(feature = "large-tuples"))]
+typed_tuple_macros::impl_typed_map_all_trait!(12);
+
+#[cfg(feature = "large-tuples")]
+typed_tuple_macros::impl_typed_map_all_trait!(32);
@@ -0,0 +1,10 @@
+//! Submodule providing the `TypedPush` trait for building a larger tuple by
+//! appending or prepending a value.
+
+typed_tuple_macros::define_typed_push_trait!();
+
+#[cfg(not(feature = "large-tuples"))]
+typed_tuple_macros::impl_typed_push_trait!(12);
+
+#[cfg(feature = "large-tuples")]
+typed_tuple_macros::impl_typed_push_trait!(32);
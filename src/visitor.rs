@@ -0,0 +1,10 @@
+//! Submodule providing heterogeneous visitor traversal over a tuple's
+//! elements, regardless of their individual types.
+
+typed_tuple_macros::define_tuple_visitor_trait!();
+
+#[cfg(not(feature = "large-tuples"))]
+typed_tuple_macros::impl_tuple_traversal_trait!(12);
+
+#[cfg(feature = "large-tuples")]
+typed_tuple_macros::impl_tuple_traversal_trait!(32);
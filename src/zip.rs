@@ -0,0 +1,10 @@
+//! Submodule providing the `TypedZip` and `TypedUnzip` traits for pairing up
+//! (and splitting apart) two equal-length tuples element by element.
+
+typed_tuple_macros::define_typed_zip_trait!();
+
+#[cfg(not(feature = "large-tuples"))]
+typed_tuple_macros::impl_typed_zip_trait!(12);
+
+#[cfg(feature = "large-tuples")]
+typed_tuple_macros::impl_typed_zip_trait!(32);
@@ -88,3 +88,477 @@ pub fn generate_typed_tuple_impls(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Generates the `TypedPush` trait definition used to build a larger tuple by
+/// appending or prepending a value.
+#[proc_macro]
+pub fn define_typed_push_trait(_input: TokenStream) -> TokenStream {
+    quote! {
+        /// Trait for growing a tuple by one element, mirroring the cons/append
+        /// operations of a heterogeneous list.
+        pub trait TypedPush<X> {
+            /// The tuple obtained by appending `X` as the new last element.
+            type PushOutput;
+            /// The tuple obtained by inserting `X` as the new first element.
+            type PrependOutput;
+
+            /// Appends `value` as the new last element of the tuple.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::TypedPush;
+            /// let tuple = ("a", 'b');
+            /// assert_eq!(tuple.push(2usize), ("a", 'b', 2usize));
+            /// ```
+            fn push(self, value: X) -> Self::PushOutput;
+
+            /// Prepends `value` as the new first element of the tuple.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::TypedPush;
+            /// let tuple = ("a", 'b');
+            /// assert_eq!(tuple.prepend(2usize), (2usize, "a", 'b'));
+            /// ```
+            fn prepend(self, value: X) -> Self::PrependOutput;
+        }
+    }
+    .into()
+}
+
+/// Generates `TypedPush` implementations for every tuple arity from the unit
+/// tuple up to `max_size - 1`, producing outputs of up to `max_size` elements.
+#[proc_macro]
+pub fn impl_typed_push_trait(input: TokenStream) -> TokenStream {
+    let max_size = parse_macro_input!(input as LitInt);
+    let max_size: usize = max_size.base10_parse().expect("Expected a number");
+
+    let mut impls = Vec::new();
+
+    for size in 0..max_size {
+        let type_params: Vec<_> = (0..size).map(|i| quote::format_ident!("T{}", i)).collect();
+        let indices: Vec<_> = (0..size).map(syn::Index::from).collect();
+
+        impls.push(quote! {
+            impl<X, #(#type_params),*> TypedPush<X> for (#(#type_params,)*) {
+                type PushOutput = (#(#type_params,)* X,);
+                type PrependOutput = (X, #(#type_params,)*);
+
+                #[inline]
+                fn push(self, value: X) -> Self::PushOutput {
+                    (#(self.#indices,)* value,)
+                }
+
+                #[inline]
+                fn prepend(self, value: X) -> Self::PrependOutput {
+                    (value, #(self.#indices,)*)
+                }
+            }
+        });
+    }
+
+    quote! {
+        #(#impls)*
+    }
+    .into()
+}
+
+/// Generates the `TypedConcat` trait definition used to join two tuples,
+/// the inverse of `TypedTuple::split_at`.
+#[proc_macro]
+pub fn define_typed_concat_trait(_input: TokenStream) -> TokenStream {
+    quote! {
+        /// Trait for joining two tuples end to end into a single larger tuple.
+        pub trait TypedConcat<Other> {
+            /// The tuple obtained by appending every element of `Other` after
+            /// every element of `Self`.
+            type Output;
+
+            /// Joins `self` and `other` into a single tuple.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::TypedConcat;
+            /// let left = ("a", 'b');
+            /// let right = (2usize,);
+            /// assert_eq!(left.concat(right), ("a", 'b', 2usize));
+            /// ```
+            fn concat(self, other: Other) -> Self::Output;
+        }
+    }
+    .into()
+}
+
+/// Generates `TypedConcat` implementations for every pair of tuple arities
+/// whose combined length does not exceed `max_size`.
+#[proc_macro]
+pub fn impl_typed_concat_trait(input: TokenStream) -> TokenStream {
+    let max_size = parse_macro_input!(input as LitInt);
+    let max_size: usize = max_size.base10_parse().expect("Expected a number");
+
+    let mut impls = Vec::new();
+
+    for left_size in 0..=max_size {
+        for right_size in 0..=(max_size - left_size) {
+            let left_params: Vec<_> =
+                (0..left_size).map(|i| quote::format_ident!("L{}", i)).collect();
+            let right_params: Vec<_> =
+                (0..right_size).map(|i| quote::format_ident!("R{}", i)).collect();
+            let left_indices: Vec<_> = (0..left_size).map(syn::Index::from).collect();
+            let right_indices: Vec<_> = (0..right_size).map(syn::Index::from).collect();
+
+            // The zero-arity case joins two unit tuples into a unit tuple, so
+            // the generated body is the literal `()` expression, which clippy
+            // otherwise flags as an unused unit.
+            let allow_unused_unit = (left_size == 0 && right_size == 0)
+                .then(|| quote! { #[allow(clippy::unused_unit)] });
+
+            impls.push(quote! {
+                impl<#(#left_params,)* #(#right_params),*> TypedConcat<(#(#right_params,)*)> for (#(#left_params,)*) {
+                    type Output = (#(#left_params,)* #(#right_params,)*);
+
+                    #allow_unused_unit
+                    #[inline]
+                    fn concat(self, other: (#(#right_params,)*)) -> Self::Output {
+                        (#(self.#left_indices,)* #(other.#right_indices,)*)
+                    }
+                }
+            });
+        }
+    }
+
+    quote! {
+        #(#impls)*
+    }
+    .into()
+}
+
+/// Generates the `TypedZip` and `TypedUnzip` trait definitions for pairing up
+/// (and splitting apart) two equal-length tuples element by element.
+#[proc_macro]
+pub fn define_typed_zip_trait(_input: TokenStream) -> TokenStream {
+    quote! {
+        /// Trait for pairing up two equal-length tuples element by element.
+        pub trait TypedZip<Other> {
+            /// The tuple of `(Self::Ti, Other::Ti)` pairs produced by zipping.
+            type Zipped;
+
+            /// Zips `self` and `other` into a tuple of element-wise pairs.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::TypedZip;
+            /// let keys = ("a", 1u8);
+            /// let values = (2usize, "b");
+            /// assert_eq!(keys.zip(values), (("a", 2usize), (1u8, "b")));
+            /// ```
+            fn zip(self, other: Other) -> Self::Zipped;
+        }
+
+        /// Trait for splitting a tuple of pairs back into two separate tuples,
+        /// the inverse of [`TypedZip`].
+        pub trait TypedUnzip {
+            /// The tuple made up of the first element of every pair.
+            type Left;
+            /// The tuple made up of the second element of every pair.
+            type Right;
+
+            /// Splits a tuple of pairs into a tuple of firsts and a tuple of
+            /// seconds.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::TypedUnzip;
+            /// let zipped = (("a", 2usize), (1u8, "b"));
+            /// assert_eq!(zipped.unzip(), (("a", 1u8), (2usize, "b")));
+            /// ```
+            fn unzip(self) -> (Self::Left, Self::Right);
+        }
+    }
+    .into()
+}
+
+/// Generates `TypedZip` and `TypedUnzip` implementations for every tuple
+/// arity up to `max_size`. `TypedZip` is only implemented between tuples of
+/// identical arity, so zipping mismatched lengths fails to compile because no
+/// impl exists for them.
+#[proc_macro]
+pub fn impl_typed_zip_trait(input: TokenStream) -> TokenStream {
+    let max_size = parse_macro_input!(input as LitInt);
+    let max_size: usize = max_size.base10_parse().expect("Expected a number");
+
+    let mut impls = Vec::new();
+
+    for size in 0..=max_size {
+        let left_params: Vec<_> = (0..size).map(|i| quote::format_ident!("A{}", i)).collect();
+        let right_params: Vec<_> = (0..size).map(|i| quote::format_ident!("B{}", i)).collect();
+        let indices: Vec<_> = (0..size).map(syn::Index::from).collect();
+
+        // The zero-arity case zips/unzips unit tuples, so the generated
+        // bodies are the literal `()` expression, which clippy otherwise
+        // flags as an unused unit.
+        let allow_unused_unit = (size == 0).then(|| quote! { #[allow(clippy::unused_unit)] });
+
+        impls.push(quote! {
+            impl<#(#left_params,)* #(#right_params),*> TypedZip<(#(#right_params,)*)> for (#(#left_params,)*) {
+                type Zipped = (#((#left_params, #right_params),)*);
+
+                #allow_unused_unit
+                #[inline]
+                fn zip(self, other: (#(#right_params,)*)) -> Self::Zipped {
+                    (#((self.#indices, other.#indices),)*)
+                }
+            }
+
+            impl<#(#left_params,)* #(#right_params),*> TypedUnzip for (#((#left_params, #right_params),)*) {
+                type Left = (#(#left_params,)*);
+                type Right = (#(#right_params,)*);
+
+                #allow_unused_unit
+                #[inline]
+                fn unzip(self) -> (Self::Left, Self::Right) {
+                    ((#(self.#indices.0,)*), (#(self.#indices.1,)*))
+                }
+            }
+        });
+    }
+
+    quote! {
+        #(#impls)*
+    }
+    .into()
+}
+
+/// Generates the `TupleVisitor` trait and the `TupleTraversal` trait that
+/// drives it across a tuple's elements, regardless of their individual types.
+#[proc_macro]
+pub fn define_tuple_visitor_trait(_input: TokenStream) -> TokenStream {
+    quote! {
+        /// Trait for a polymorphic operation (serialize, debug-print, hash, ...)
+        /// that can be run against any field of a tuple, regardless of its type.
+        pub trait TupleVisitor {
+            /// Visits a shared reference to a field.
+            fn visit<T>(&mut self, value: &T);
+
+            /// Visits a mutable reference to a field.
+            fn visit_mut<T>(&mut self, value: &mut T);
+
+            /// Visits an owned field.
+            fn visit_owned<T>(&mut self, value: T);
+        }
+
+        /// Trait for driving a [`TupleVisitor`] across every field of a tuple,
+        /// in index order.
+        /// # Example
+        /// ```
+        /// # use typed_tuple::{TupleTraversal, TupleVisitor};
+        /// struct Counter(usize);
+        /// impl TupleVisitor for Counter {
+        ///     fn visit<T>(&mut self, _value: &T) { self.0 += 1; }
+        ///     fn visit_mut<T>(&mut self, _value: &mut T) { self.0 += 1; }
+        ///     fn visit_owned<T>(&mut self, _value: T) { self.0 += 1; }
+        /// }
+        ///
+        /// let tuple = ("a", 'b', 2usize);
+        /// let mut counter = Counter(0);
+        /// tuple.for_each_ref(&mut counter);
+        /// assert_eq!(counter.0, 3);
+        /// ```
+        pub trait TupleTraversal {
+            /// Visits every field by shared reference, in index order.
+            fn for_each_ref<V: TupleVisitor>(&self, v: &mut V);
+
+            /// Visits every field by mutable reference, in index order.
+            fn for_each_mut<V: TupleVisitor>(&mut self, v: &mut V);
+
+            /// Consumes the tuple, visiting every field by value, in index order.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::{TupleTraversal, TupleVisitor};
+            /// struct Counter(usize);
+            /// impl TupleVisitor for Counter {
+            ///     fn visit<T>(&mut self, _value: &T) { self.0 += 1; }
+            ///     fn visit_mut<T>(&mut self, _value: &mut T) { self.0 += 1; }
+            ///     fn visit_owned<T>(&mut self, _value: T) { self.0 += 1; }
+            /// }
+            ///
+            /// let tuple = ("a", 'b', 2usize);
+            /// let mut counter = Counter(0);
+            /// tuple.into_for_each(&mut counter);
+            /// assert_eq!(counter.0, 3);
+            /// ```
+            fn into_for_each<V: TupleVisitor>(self, v: &mut V);
+        }
+    }
+    .into()
+}
+
+/// Generates `TupleTraversal` implementations for every tuple arity up to
+/// `max_size`.
+#[proc_macro]
+pub fn impl_tuple_traversal_trait(input: TokenStream) -> TokenStream {
+    let max_size = parse_macro_input!(input as LitInt);
+    let max_size: usize = max_size.base10_parse().expect("Expected a number");
+
+    let mut impls = Vec::new();
+
+    for size in 0..=max_size {
+        let type_params: Vec<_> = (0..size).map(|i| quote::format_ident!("T{}", i)).collect();
+        let indices: Vec<_> = (0..size).map(syn::Index::from).collect();
+
+        impls.push(quote! {
+            impl<#(#type_params),*> TupleTraversal for (#(#type_params,)*) {
+                #[inline]
+                fn for_each_ref<V: TupleVisitor>(&self, v: &mut V) {
+                    #( v.visit(&self.#indices); )*
+                }
+
+                #[inline]
+                fn for_each_mut<V: TupleVisitor>(&mut self, v: &mut V) {
+                    #( v.visit_mut(&mut self.#indices); )*
+                }
+
+                #[inline]
+                fn into_for_each<V: TupleVisitor>(self, v: &mut V) {
+                    #( v.visit_owned(self.#indices); )*
+                }
+            }
+        });
+    }
+
+    quote! {
+        #(#impls)*
+    }
+    .into()
+}
+
+/// Generates the `TupleMapper` trait used to transform every field of a
+/// tuple at once.
+#[proc_macro]
+pub fn define_tuple_mapper_trait(_input: TokenStream) -> TokenStream {
+    quote! {
+        /// Trait for a polymorphic transformation that can be applied to every
+        /// field of a tuple, regardless of its type. Unlike `TypedTuple::map`,
+        /// values are moved through rather than taken from a `Default`, so `T`
+        /// need not implement `Default`.
+        pub trait TupleMapper {
+            /// Transforms a single field, returning its replacement.
+            fn map<T>(&mut self, value: T) -> T;
+        }
+
+        /// Trait for applying a [`TupleMapper`] to every field of a tuple at
+        /// once, reconstructing the tuple from the results.
+        pub trait TypedMapAll {
+            /// Applies `m` to every field, in index order, and reconstructs
+            /// the tuple from the results.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::{TupleMapper, TypedMapAll};
+            /// struct Identity;
+            /// impl TupleMapper for Identity {
+            ///     fn map<T>(&mut self, value: T) -> T { value }
+            /// }
+            ///
+            /// let tuple = ("a", 'b', 2usize);
+            /// assert_eq!(tuple.map_all(&mut Identity), ("a", 'b', 2usize));
+            /// ```
+            fn map_all<M: TupleMapper>(self, m: &mut M) -> Self;
+        }
+    }
+    .into()
+}
+
+/// Generates `TypedMapAll` implementations for every tuple arity up to
+/// `max_size`.
+#[proc_macro]
+pub fn impl_typed_map_all_trait(input: TokenStream) -> TokenStream {
+    let max_size = parse_macro_input!(input as LitInt);
+    let max_size: usize = max_size.base10_parse().expect("Expected a number");
+
+    let mut impls = Vec::new();
+
+    for size in 0..=max_size {
+        let type_params: Vec<_> = (0..size).map(|i| quote::format_ident!("T{}", i)).collect();
+        let indices: Vec<_> = (0..size).map(syn::Index::from).collect();
+
+        // The zero-arity case maps a unit tuple, so the generated body is
+        // the literal `()` expression, which clippy otherwise flags as an
+        // unused unit.
+        let allow_unused_unit = (size == 0).then(|| quote! { #[allow(clippy::unused_unit)] });
+
+        impls.push(quote! {
+            impl<#(#type_params),*> TypedMapAll for (#(#type_params,)*) {
+                #allow_unused_unit
+                #[inline]
+                fn map_all<M: TupleMapper>(self, m: &mut M) -> Self {
+                    (#( m.map(self.#indices), )*)
+                }
+            }
+        });
+    }
+
+    quote! {
+        #(#impls)*
+    }
+    .into()
+}
+
+/// Generates the `TypedLast` trait definition used to access the last element
+/// of a tuple by type.
+#[proc_macro]
+pub fn define_typed_last_trait(_input: TokenStream) -> TokenStream {
+    quote! {
+        /// Trait for accessing the last element of a tuple by type.
+        pub trait TypedLast<T> {
+            /// Get a reference to the last element of the tuple.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::TypedLast;
+            /// let tuple = ("a", 'b', 2usize);
+            /// let last: &usize = tuple.last();
+            /// assert_eq!(*last, 2usize);
+            /// ```
+            fn last(&self) -> &T;
+
+            /// Get a mutable reference to the last element of the tuple.
+            /// # Example
+            /// ```
+            /// # use typed_tuple::TypedLast;
+            /// let mut tuple = ("a", 'b', 2usize);
+            /// *tuple.last_mut() = 3usize;
+            /// assert_eq!(tuple, ("a", 'b', 3usize));
+            /// ```
+            fn last_mut(&mut self) -> &mut T;
+        }
+    }
+    .into()
+}
+
+/// Generates `TypedLast` implementations for every non-empty tuple arity up
+/// to the specified size.
+#[proc_macro]
+pub fn impl_typed_last_trait(input: TokenStream) -> TokenStream {
+    let max_size = parse_macro_input!(input as LitInt);
+    let max_size: usize = max_size.base10_parse().expect("Expected a number");
+
+    let mut impls = Vec::new();
+
+    for size in 1..=max_size {
+        let type_params: Vec<_> = (0..size).map(|i| quote::format_ident!("T{}", i)).collect();
+        let last_param = &type_params[size - 1];
+        let last_index = syn::Index::from(size - 1);
+
+        impls.push(quote! {
+            impl<#(#type_params),*> TypedLast<#last_param> for (#(#type_params,)*) {
+                #[inline]
+                fn last(&self) -> &#last_param {
+                    &self.#last_index
+                }
+                #[inline]
+                fn last_mut(&mut self) -> &mut #last_param {
+                    &mut self.#last_index
+                }
+            }
+        });
+    }
+
+    quote! {
+        #(#impls)*
+    }
+    .into()
+}